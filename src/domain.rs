@@ -0,0 +1,21 @@
+use std::hash::Hash;
+
+use crate::IndexedDomain;
+
+impl<T: Clone + Eq + Hash> IndexedDomain<T> {
+    /// Appends `value` to the domain if it isn't already present, returning its index either
+    /// way. This lets callers discover the universe of values incrementally (as is common in
+    /// graph/worklist algorithms where nodes appear during traversal) instead of enumerating it
+    /// up front, at the cost of rebuilding the domain's internal tables on every genuinely new
+    /// value.
+    pub fn append(&mut self, value: T) -> usize {
+        if let Some(index) = self.iter().position(|existing| *existing == value) {
+            return index;
+        }
+
+        let mut values: Vec<T> = self.iter().cloned().collect();
+        values.push(value);
+        *self = IndexedDomain::from_iter(values);
+        self.len() - 1
+    }
+}
@@ -54,6 +54,28 @@ impl BitSet for BitVec {
         other_copy.invert();
         self.intersect(&other_copy)
     }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        (self.clone() & !other.clone()).not_any()
+    }
+
+    fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        (self.clone() & other.clone()).not_any()
+    }
+
+    fn intersect_len(&self, other: &Self) -> usize {
+        (self.clone() & other.clone()).count_ones()
+    }
+
+    fn grow(&mut self, new_size: usize) {
+        if new_size > self.len() {
+            self.resize(new_size, false);
+        }
+    }
 }
 
 /// [`IndexSet`] specialized to the [`BitVec`] implementation.
@@ -72,3 +94,37 @@ pub type BitvecArcIndexMatrix<R, C> = IndexMatrix<R, C, BitVec, ArcFamily>;
 fn test_bitvec() {
     crate::test_utils::impl_test::<BitVec>();
 }
+
+#[test]
+fn test_bitvec_relational_queries() {
+    let mut a: BitVec = BitSet::empty(8);
+    BitSet::insert(&mut a, 0);
+    BitSet::insert(&mut a, 1);
+
+    let mut b: BitVec = BitSet::empty(8);
+    BitSet::insert(&mut b, 0);
+    BitSet::insert(&mut b, 1);
+    BitSet::insert(&mut b, 2);
+
+    assert!(BitSet::is_subset(&a, &b));
+    assert!(!BitSet::is_subset(&b, &a));
+    assert!(BitSet::is_superset(&b, &a));
+    assert_eq!(BitSet::intersect_len(&a, &b), 2);
+
+    let mut c: BitVec = BitSet::empty(8);
+    BitSet::insert(&mut c, 3);
+    assert!(BitSet::is_disjoint(&a, &c));
+    assert!(!BitSet::is_disjoint(&a, &b));
+}
+
+#[test]
+fn test_bitvec_grow() {
+    let mut set: BitVec = BitSet::empty(4);
+    BitSet::insert(&mut set, 1);
+
+    BitSet::grow(&mut set, 10);
+
+    assert!(BitSet::contains(&set, 1));
+    BitSet::insert(&mut set, 9);
+    assert!(BitSet::contains(&set, 9));
+}
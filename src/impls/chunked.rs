@@ -0,0 +1,489 @@
+use std::rc::Rc;
+
+use crate::{ArcFamily, BitSet, IndexMatrix, IndexSet, RcFamily};
+
+type Word = u64;
+const WORD_BITS: usize = Word::BITS as usize;
+
+/// Number of bits held by each chunk of a [`ChunkedBitSet`].
+const CHUNK_BITS: usize = 2048;
+const CHUNK_WORDS: usize = CHUNK_BITS / WORD_BITS;
+
+fn num_words(bits: usize) -> usize {
+    (bits + WORD_BITS - 1) / WORD_BITS
+}
+
+/// One fixed-size slice of a [`ChunkedBitSet`]'s domain.
+///
+/// All-zero and all-ones chunks carry no word allocation; only a `Mixed` chunk owns words, and
+/// those words are reference-counted so that cloning a chunk is O(1) (and cloning the whole set
+/// O(num_chunks)) until the clone is actually mutated.
+#[derive(Clone, Debug)]
+enum Chunk {
+    Zeros(usize),
+    Ones(usize),
+    Mixed(usize, Rc<[Word]>),
+}
+
+impl Chunk {
+    fn count(&self) -> usize {
+        match self {
+            Chunk::Zeros(_) => 0,
+            Chunk::Ones(count) | Chunk::Mixed(count, _) => *count,
+        }
+    }
+}
+
+/// A copy-on-write bit-set, partitioned into fixed-size chunks that are stored as `Zeros`,
+/// `Ones`, or a reference-counted slice of mixed words.
+///
+/// Ported from rustc's chunked bitset representation. Cloning only bumps `Rc` refcounts for
+/// mixed chunks, making `clone` O(num_chunks) rather than O(domain_bits); mutation copies a
+/// chunk's words on first write via [`Rc::make_mut`]. This keeps the `JoinSemiLattice` impls for
+/// `IndexSet`/`IndexMatrix` cheap when fixpoint iteration repeatedly clones and unions large
+/// states.
+#[derive(Clone, Debug)]
+pub struct ChunkedBitSet {
+    domain_size: usize,
+    chunks: Vec<Chunk>,
+}
+
+impl ChunkedBitSet {
+    fn chunk_bits(chunk_index: usize, domain_size: usize) -> usize {
+        let start = chunk_index * CHUNK_BITS;
+        CHUNK_BITS.min(domain_size - start)
+    }
+
+    fn mixed_words(bits: usize, fill: bool) -> Rc<[Word]> {
+        let words = num_words(bits);
+        let mut data = vec![if fill { Word::MAX } else { 0 }; words];
+        if fill {
+            let rem = bits % WORD_BITS;
+            if rem != 0 {
+                if let Some(last) = data.last_mut() {
+                    *last &= (1 << rem) - 1;
+                }
+            }
+        }
+        data.into()
+    }
+}
+
+impl BitSet for ChunkedBitSet {
+    type Iter<'a> = ChunkedBitSetIter<'a>;
+
+    fn empty(size: usize) -> Self {
+        let chunk_count = if size == 0 { 0 } else { size.div_ceil(CHUNK_BITS) };
+        let chunks = (0..chunk_count)
+            .map(|i| Chunk::Zeros(Self::chunk_bits(i, size)))
+            .collect();
+        ChunkedBitSet {
+            domain_size: size,
+            chunks,
+        }
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        let chunk_index = index / CHUNK_BITS;
+        let bit_in_chunk = index % CHUNK_BITS;
+        match &self.chunks[chunk_index] {
+            Chunk::Zeros(_) => false,
+            Chunk::Ones(_) => true,
+            Chunk::Mixed(_, words) => {
+                let word = words[bit_in_chunk / WORD_BITS];
+                word & (1 << (bit_in_chunk % WORD_BITS)) != 0
+            }
+        }
+    }
+
+    fn insert(&mut self, index: usize) -> bool {
+        let chunk_index = index / CHUNK_BITS;
+        let bit_in_chunk = index % CHUNK_BITS;
+        let chunk = &mut self.chunks[chunk_index];
+        match chunk {
+            Chunk::Ones(_) => false,
+            Chunk::Zeros(_) => {
+                let bits = Self::chunk_bits(chunk_index, self.domain_size);
+                if bits == 1 {
+                    *chunk = Chunk::Ones(1);
+                    return true;
+                }
+                let mut words = Self::mixed_words(bits, false);
+                Rc::make_mut(&mut words)[bit_in_chunk / WORD_BITS] |=
+                    1 << (bit_in_chunk % WORD_BITS);
+                *chunk = Chunk::Mixed(1, words);
+                true
+            }
+            Chunk::Mixed(count, words) => {
+                let word = Rc::make_mut(words);
+                let w = &mut word[bit_in_chunk / WORD_BITS];
+                let mask = 1 << (bit_in_chunk % WORD_BITS);
+                if *w & mask != 0 {
+                    return false;
+                }
+                *w |= mask;
+                *count += 1;
+                let bits = Self::chunk_bits(chunk_index, self.domain_size);
+                if *count == bits {
+                    *chunk = Chunk::Ones(bits);
+                }
+                true
+            }
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        ChunkedBitSetIter {
+            set: self,
+            chunk_index: 0,
+            bit_in_chunk: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.chunks.iter().map(Chunk::count).sum()
+    }
+
+    fn union(&mut self, other: &Self) -> bool {
+        let mut changed = false;
+        for (chunk_index, other_chunk) in other.chunks.iter().enumerate() {
+            let bits = Self::chunk_bits(chunk_index, self.domain_size);
+            changed |= Self::union_chunk(&mut self.chunks[chunk_index], other_chunk, bits);
+        }
+        changed
+    }
+
+    fn intersect(&mut self, other: &Self) -> bool {
+        let mut changed = false;
+        for (chunk_index, other_chunk) in other.chunks.iter().enumerate() {
+            let bits = Self::chunk_bits(chunk_index, self.domain_size);
+            changed |= Self::intersect_chunk(&mut self.chunks[chunk_index], other_chunk, bits);
+        }
+        changed
+    }
+
+    fn subtract(&mut self, other: &Self) -> bool {
+        let mut changed = false;
+        for (chunk_index, other_chunk) in other.chunks.iter().enumerate() {
+            let bits = Self::chunk_bits(chunk_index, self.domain_size);
+            changed |= Self::subtract_chunk(&mut self.chunks[chunk_index], other_chunk, bits);
+        }
+        changed
+    }
+
+    fn invert(&mut self) {
+        for (chunk_index, chunk) in self.chunks.iter_mut().enumerate() {
+            let bits = Self::chunk_bits(chunk_index, self.domain_size);
+            *chunk = match chunk {
+                Chunk::Zeros(_) => Chunk::Ones(bits),
+                Chunk::Ones(_) => Chunk::Zeros(bits),
+                Chunk::Mixed(count, words) => {
+                    let mut inverted = Self::mixed_words(bits, true);
+                    {
+                        let dst = Rc::make_mut(&mut inverted);
+                        for (w, orig) in dst.iter_mut().zip(words.iter()) {
+                            *w &= !orig;
+                        }
+                    }
+                    Chunk::Mixed(bits - *count, inverted)
+                }
+            };
+        }
+    }
+
+    fn clear(&mut self) {
+        for (chunk_index, chunk) in self.chunks.iter_mut().enumerate() {
+            *chunk = Chunk::Zeros(Self::chunk_bits(chunk_index, self.domain_size));
+        }
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        self.chunks
+            .iter()
+            .zip(other.chunks.iter())
+            .all(|(this, other)| Self::chunk_is_subset(this, other))
+    }
+
+    fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        self.chunks
+            .iter()
+            .zip(other.chunks.iter())
+            .all(|(this, other)| Self::chunk_intersect_len(this, other) == 0)
+    }
+
+    fn intersect_len(&self, other: &Self) -> usize {
+        self.chunks
+            .iter()
+            .zip(other.chunks.iter())
+            .map(|(this, other)| Self::chunk_intersect_len(this, other))
+            .sum()
+    }
+
+    fn grow(&mut self, new_size: usize) {
+        if new_size <= self.domain_size {
+            return;
+        }
+
+        let elems: Vec<usize> = self.iter().collect();
+        *self = ChunkedBitSet::empty(new_size);
+        for i in elems {
+            self.insert(i);
+        }
+    }
+}
+
+impl ChunkedBitSet {
+    /// Converts a `Mixed` chunk back to `Zeros`/`Ones` if its recomputed population has reached
+    /// either extreme, keeping the representation (and therefore `chunk_is_subset`'s fast paths)
+    /// canonical.
+    fn canonicalize(chunk: &mut Chunk, bits: usize) {
+        if let Chunk::Mixed(count, _) = chunk {
+            if *count == 0 {
+                *chunk = Chunk::Zeros(bits);
+            } else if *count == bits {
+                *chunk = Chunk::Ones(bits);
+            }
+        }
+    }
+
+    fn union_chunk(chunk: &mut Chunk, other: &Chunk, bits: usize) -> bool {
+        match (&mut *chunk, other) {
+            (_, Chunk::Zeros(_)) => false,
+            (Chunk::Ones(_), _) => false,
+            (_, Chunk::Ones(count)) => {
+                let changed = chunk.count() != *count;
+                *chunk = Chunk::Ones(*count);
+                changed
+            }
+            (Chunk::Zeros(_), Chunk::Mixed(count, words)) => {
+                *chunk = Chunk::Mixed(*count, words.clone());
+                *count > 0
+            }
+            (Chunk::Mixed(count, words), Chunk::Mixed(_, other_words)) => {
+                let before = *count;
+                let dst = Rc::make_mut(words);
+                for (w, o) in dst.iter_mut().zip(other_words.iter()) {
+                    *w |= o;
+                }
+                *count = dst.iter().map(|w| w.count_ones() as usize).sum();
+                let changed = *count != before;
+                Self::canonicalize(chunk, bits);
+                changed
+            }
+        }
+    }
+
+    fn intersect_chunk(chunk: &mut Chunk, other: &Chunk, bits: usize) -> bool {
+        match (&mut *chunk, other) {
+            (Chunk::Zeros(_), _) => false,
+            (_, Chunk::Ones(_)) => false,
+            (_, Chunk::Zeros(count)) => {
+                let changed = chunk.count() != 0;
+                *chunk = Chunk::Zeros(*count);
+                changed
+            }
+            (Chunk::Ones(count), Chunk::Mixed(other_count, words)) => {
+                let changed = *count != *other_count;
+                *chunk = Chunk::Mixed(*other_count, words.clone());
+                Self::canonicalize(chunk, bits);
+                changed
+            }
+            (Chunk::Mixed(count, words), Chunk::Mixed(_, other_words)) => {
+                let before = *count;
+                let dst = Rc::make_mut(words);
+                for (w, o) in dst.iter_mut().zip(other_words.iter()) {
+                    *w &= o;
+                }
+                *count = dst.iter().map(|w| w.count_ones() as usize).sum();
+                let changed = *count != before;
+                Self::canonicalize(chunk, bits);
+                changed
+            }
+        }
+    }
+
+    fn chunk_is_subset(chunk: &Chunk, other: &Chunk) -> bool {
+        match (chunk, other) {
+            (Chunk::Zeros(_), _) => true,
+            (_, Chunk::Ones(_)) => true,
+            (Chunk::Ones(_), _) => false,
+            (_, Chunk::Zeros(_)) => chunk.count() == 0,
+            (Chunk::Mixed(_, words), Chunk::Mixed(_, other_words)) => words
+                .iter()
+                .zip(other_words.iter())
+                .all(|(w, o)| w & !o == 0),
+        }
+    }
+
+    fn chunk_intersect_len(chunk: &Chunk, other: &Chunk) -> usize {
+        match (chunk, other) {
+            (Chunk::Zeros(_), _) | (_, Chunk::Zeros(_)) => 0,
+            (Chunk::Ones(count), _) => other.count().min(*count),
+            (_, Chunk::Ones(count)) => chunk.count().min(*count),
+            (Chunk::Mixed(_, words), Chunk::Mixed(_, other_words)) => words
+                .iter()
+                .zip(other_words.iter())
+                .map(|(w, o)| (w & o).count_ones() as usize)
+                .sum(),
+        }
+    }
+
+    fn subtract_chunk(chunk: &mut Chunk, other: &Chunk, bits: usize) -> bool {
+        match (&mut *chunk, other) {
+            (Chunk::Zeros(_), _) => false,
+            (_, Chunk::Zeros(_)) => false,
+            (Chunk::Ones(_), Chunk::Ones(_)) => {
+                *chunk = Chunk::Zeros(bits);
+                true
+            }
+            (Chunk::Mixed(count, _), Chunk::Ones(_)) => {
+                let changed = *count != 0;
+                *chunk = Chunk::Zeros(bits);
+                changed
+            }
+            (Chunk::Ones(_), Chunk::Mixed(other_count, other_words)) => {
+                let mut kept = Self::mixed_words(bits, true);
+                {
+                    let dst = Rc::make_mut(&mut kept);
+                    for (w, o) in dst.iter_mut().zip(other_words.iter()) {
+                        *w &= !o;
+                    }
+                }
+                let changed = *other_count != 0;
+                *chunk = Chunk::Mixed(bits - *other_count, kept);
+                changed
+            }
+            (Chunk::Mixed(count, words), Chunk::Mixed(_, other_words)) => {
+                let before = *count;
+                let dst = Rc::make_mut(words);
+                for (w, o) in dst.iter_mut().zip(other_words.iter()) {
+                    *w &= !o;
+                }
+                *count = dst.iter().map(|w| w.count_ones() as usize).sum();
+                let changed = *count != before;
+                Self::canonicalize(chunk, bits);
+                changed
+            }
+        }
+    }
+}
+
+/// Iterator over the set bits of a [`ChunkedBitSet`].
+pub struct ChunkedBitSetIter<'a> {
+    set: &'a ChunkedBitSet,
+    chunk_index: usize,
+    bit_in_chunk: usize,
+}
+
+impl Iterator for ChunkedBitSetIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        while self.chunk_index < self.set.chunks.len() {
+            let bits = ChunkedBitSet::chunk_bits(self.chunk_index, self.set.domain_size);
+            let chunk = &self.set.chunks[self.chunk_index];
+            while self.bit_in_chunk < bits {
+                let bit = self.bit_in_chunk;
+                self.bit_in_chunk += 1;
+                let set = match chunk {
+                    Chunk::Zeros(_) => false,
+                    Chunk::Ones(_) => true,
+                    Chunk::Mixed(_, words) => {
+                        words[bit / WORD_BITS] & (1 << (bit % WORD_BITS)) != 0
+                    }
+                };
+                if set {
+                    return Some(self.chunk_index * CHUNK_BITS + bit);
+                }
+            }
+            self.chunk_index += 1;
+            self.bit_in_chunk = 0;
+        }
+        None
+    }
+}
+
+/// [`IndexSet`] specialized to the [`ChunkedBitSet`] implementation.
+pub type ChunkedIndexSet<T> = IndexSet<T, ChunkedBitSet, RcFamily>;
+
+/// [`IndexSet`] specialized to the [`ChunkedBitSet`] implementation with the [`ArcFamily`].
+pub type ChunkedArcIndexSet<T> = IndexSet<T, ChunkedBitSet, ArcFamily>;
+
+/// [`IndexMatrix`] specialized to the [`ChunkedBitSet`] implementation.
+pub type ChunkedIndexMatrix<R, C> = IndexMatrix<R, C, ChunkedBitSet, RcFamily>;
+
+/// [`IndexMatrix`] specialized to the [`ChunkedBitSet`] implementation with the [`ArcFamily`].
+pub type ChunkedArcIndexMatrix<R, C> = IndexMatrix<R, C, ChunkedBitSet, ArcFamily>;
+
+#[test]
+fn test_chunked_bitset() {
+    crate::test_utils::impl_test::<ChunkedBitSet>();
+}
+
+#[test]
+fn test_chunked_bitset_union_canonicalizes_to_ones() {
+    // Two complementary partial `Mixed` chunks, unioned together, must collapse to `Ones` rather
+    // than staying `Mixed` with every bit set — otherwise `is_subset` against a literal `Ones`
+    // set wrongly reports `false` for bit-for-bit identical sets.
+    let mut evens = ChunkedBitSet::empty(4);
+    let mut odds = ChunkedBitSet::empty(4);
+    for i in 0..4 {
+        if i % 2 == 0 {
+            evens.insert(i);
+        } else {
+            odds.insert(i);
+        }
+    }
+
+    evens.union(&odds);
+
+    let mut full = ChunkedBitSet::empty(4);
+    full.invert();
+
+    assert!(evens.is_subset(&full));
+    assert!(full.is_subset(&evens));
+    assert_eq!(evens.len(), 4);
+}
+
+
+#[test]
+fn test_chunked_bitset_relational_queries() {
+    let mut a = ChunkedBitSet::empty(8);
+    a.insert(0);
+    a.insert(1);
+
+    let mut b = ChunkedBitSet::empty(8);
+    b.insert(0);
+    b.insert(1);
+    b.insert(2);
+
+    assert!(a.is_subset(&b));
+    assert!(!b.is_subset(&a));
+    assert!(b.is_superset(&a));
+    assert!(!a.is_superset(&b));
+    assert_eq!(a.intersect_len(&b), 2);
+
+    let mut c = ChunkedBitSet::empty(8);
+    c.insert(3);
+    assert!(a.is_disjoint(&c));
+    assert!(!a.is_disjoint(&b));
+}
+
+#[test]
+fn test_chunked_bitset_grow() {
+    let mut set = ChunkedBitSet::empty(4);
+    set.insert(1);
+    set.insert(3);
+
+    set.grow(10);
+
+    assert!(set.contains(1));
+    assert!(set.contains(3));
+    assert!(!set.contains(9));
+    set.insert(9);
+    assert!(set.contains(9));
+}
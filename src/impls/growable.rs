@@ -0,0 +1,181 @@
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::rc::Rc;
+
+use crate::{BitSet, IndexMatrix, IndexSet, IndexedDomain, IndexedValue, RcFamily};
+
+/// A domain that one or more [`GrowableIndexSet`]s/[`GrowableIndexMatrix`]es can share and
+/// append new values to via [`IndexedDomain::append`].
+///
+/// A plain `IndexSet`/`IndexMatrix` borrows its domain behind `P::Pointer` (typically `Rc`),
+/// which is immutable once shared; growing it in place would require every other holder of that
+/// `Rc` to observe the change, which shared ownership alone can't give us. Wrapping the domain
+/// in a `RefCell` here is what makes growth observable: anyone holding this handle sees the
+/// latest domain the next time they borrow it.
+pub type GrowableDomain<T> = Rc<RefCell<IndexedDomain<T>>>;
+
+/// Creates a fresh, empty [`GrowableDomain`].
+pub fn new_growable_domain<T: Clone + Eq + Hash>() -> GrowableDomain<T> {
+    Rc::new(RefCell::new(IndexedDomain::from_iter(std::iter::empty())))
+}
+
+/// An opt-in growable [`IndexSet`] that interns new values into a shared [`GrowableDomain`] on
+/// demand, instead of requiring the whole universe of values to be known up front.
+///
+/// Internally this is a real [`IndexSet`] rebuilt against a fresh domain snapshot each time an
+/// insert grows the domain; only `insert` pays the cost of a new value. The wrapper exposes
+/// `insert`, `contains`, `iter`, `len` and `is_empty`; set operations between two growable sets
+/// (union, intersection, subtraction) are not provided.
+pub struct GrowableIndexSet<T: IndexedValue + Clone + Eq + Hash, S: BitSet> {
+    domain: GrowableDomain<T>,
+    snapshot: Rc<IndexedDomain<T>>,
+    set: IndexSet<T, S, RcFamily>,
+}
+
+impl<T: IndexedValue + Clone + Eq + Hash, S: BitSet> GrowableIndexSet<T, S> {
+    /// Creates an empty set over `domain`.
+    pub fn new(domain: &GrowableDomain<T>) -> Self {
+        let snapshot = Rc::new(domain.borrow().clone());
+        let set = IndexSet::new(&snapshot);
+        GrowableIndexSet {
+            domain: domain.clone(),
+            snapshot,
+            set,
+        }
+    }
+
+    /// Interns `value` into the shared domain if it's new, then inserts it into the set.
+    /// Returns true if `self` changed.
+    pub fn insert(&mut self, value: T) -> bool {
+        self.domain.borrow_mut().append(value.clone());
+
+        let stale = self.snapshot.len() != self.domain.borrow().len();
+        if stale {
+            self.snapshot = Rc::new(self.domain.borrow().clone());
+            let mut rebuilt = IndexSet::new(&self.snapshot);
+            for existing in self.set.iter() {
+                rebuilt.insert(existing.clone());
+            }
+            self.set = rebuilt;
+        }
+
+        self.set.insert(value)
+    }
+
+    /// Returns true if `value` has been interned and is a member of the set.
+    pub fn contains(&self, value: &T) -> bool {
+        self.snapshot.iter().any(|existing| existing == value) && self.set.contains(value)
+    }
+
+    /// Returns an iterator over the elements currently in the set.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.set.iter()
+    }
+
+    /// Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.set.len()
+    }
+
+    /// Returns true if the set has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A growable counterpart to [`IndexMatrix`]: rows are plain hashable keys, columns are interned
+/// into a shared [`GrowableDomain`] on demand, and the matrix is rebuilt against a fresh snapshot
+/// whenever a new column appears.
+pub struct GrowableIndexMatrix<
+    R: Eq + Hash + Clone,
+    C: IndexedValue + Clone + Eq + Hash,
+    S: BitSet,
+> {
+    domain: GrowableDomain<C>,
+    snapshot: Rc<IndexedDomain<C>>,
+    matrix: IndexMatrix<R, C, S, RcFamily>,
+}
+
+impl<R: Eq + Hash + Clone, C: IndexedValue + Clone + Eq + Hash, S: BitSet>
+    GrowableIndexMatrix<R, C, S>
+{
+    /// Creates an empty matrix over `domain`.
+    pub fn new(domain: &GrowableDomain<C>) -> Self {
+        let snapshot = Rc::new(domain.borrow().clone());
+        let matrix = IndexMatrix::new(&snapshot);
+        GrowableIndexMatrix {
+            domain: domain.clone(),
+            snapshot,
+            matrix,
+        }
+    }
+
+    /// Inserts a pair `(row, col)` into the matrix, interning `col` into the shared domain if
+    /// it's new. Returns true if `self` changed.
+    pub fn insert(&mut self, row: R, col: C) -> bool {
+        self.domain.borrow_mut().append(col.clone());
+
+        let stale = self.snapshot.len() != self.domain.borrow().len();
+        if stale {
+            self.snapshot = Rc::new(self.domain.borrow().clone());
+            let mut rebuilt = IndexMatrix::new(&self.snapshot);
+            for (row, cols) in self.matrix.rows() {
+                for existing in cols.iter() {
+                    rebuilt.insert(row.clone(), existing.clone());
+                }
+            }
+            self.matrix = rebuilt;
+        }
+
+        self.matrix.insert(row, col)
+    }
+
+    /// Returns an iterator over the elements in `row`.
+    pub fn row(&self, row: &R) -> impl Iterator<Item = &C> + '_ {
+        self.matrix.row(row)
+    }
+}
+
+#[test]
+fn test_growable_index_set() {
+    use crate::impls::bv::bitvec::vec::BitVec;
+
+    let domain = new_growable_domain::<&'static str>();
+    let mut set = GrowableIndexSet::<&'static str, BitVec>::new(&domain);
+    assert!(set.insert("a"));
+    assert!(!set.insert("a"));
+    assert!(set.insert("b"));
+    assert!(set.contains(&"a"));
+    assert!(set.contains(&"b"));
+    assert!(!set.contains(&"c"));
+    assert_eq!(set.len(), 2);
+}
+
+#[test]
+fn test_growable_index_matrix() {
+    use crate::impls::bv::bitvec::vec::BitVec;
+
+    let domain = new_growable_domain::<&'static str>();
+    let mut mtx = GrowableIndexMatrix::<usize, &'static str, BitVec>::new(&domain);
+    assert!(mtx.insert(0, "a"));
+    assert!(mtx.insert(0, "b"));
+    assert!(mtx.insert(1, "c"));
+    assert_eq!(mtx.row(&0).collect::<Vec<_>>(), vec![&"a", &"b"]);
+    assert_eq!(mtx.row(&1).collect::<Vec<_>>(), vec![&"c"]);
+}
+
+#[test]
+fn test_growable_index_set_shared_domain() {
+    use crate::impls::bv::bitvec::vec::BitVec;
+
+    let domain = new_growable_domain::<&'static str>();
+    let mut a = GrowableIndexSet::<&'static str, BitVec>::new(&domain);
+    let mut b = GrowableIndexSet::<&'static str, BitVec>::new(&domain);
+
+    // `a` interns "x", growing the shared domain behind `b`'s back.
+    assert!(a.insert("x"));
+    // `b` inserting the same, now-already-interned value must still resync against the grown
+    // domain instead of inserting into its stale, smaller snapshot.
+    assert!(b.insert("x"));
+    assert!(b.contains(&"x"));
+}
@@ -0,0 +1,254 @@
+use std::slice;
+
+use smallvec::SmallVec;
+
+use crate::impls::bv::bitvec::vec::BitVec;
+use crate::{ArcFamily, BitSet, IndexMatrix, IndexSet, RcFamily};
+
+/// Number of elements a [`HybridBitSet`] can hold in its sparse representation before it is
+/// promoted to a dense, word-backed bitset.
+const SPARSE_MAX: usize = 8;
+
+/// A bit-set that represents itself as a small sorted list of indices while it's nearly empty,
+/// and promotes to a dense [`BitVec`] once it grows past [`SPARSE_MAX`] elements.
+///
+/// Modeled on rustc's sparse-then-dense `HybridBitSet`. `IndexMatrix` rows and dataflow states
+/// are frequently near-empty over a large column domain; storing them sparsely avoids paying
+/// for a full dense bit-vector in the common case.
+#[derive(Clone, Debug)]
+pub enum HybridBitSet {
+    Sparse(SmallVec<[usize; SPARSE_MAX]>, usize),
+    Dense(BitVec),
+}
+
+/// Iterator over the elements of a [`HybridBitSet`].
+pub enum HybridBitSetIter<'a> {
+    Sparse(slice::Iter<'a, usize>),
+    Dense(<BitVec as BitSet>::Iter<'a>),
+}
+
+impl Iterator for HybridBitSetIter<'_> {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        match self {
+            HybridBitSetIter::Sparse(iter) => iter.next().copied(),
+            HybridBitSetIter::Dense(iter) => iter.next(),
+        }
+    }
+}
+
+impl HybridBitSet {
+    fn domain_size(&self) -> usize {
+        match self {
+            HybridBitSet::Sparse(_, size) => *size,
+            HybridBitSet::Dense(bits) => bits.len(),
+        }
+    }
+
+    fn densify(&mut self) {
+        if let HybridBitSet::Sparse(elems, size) = self {
+            let mut dense = BitVec::empty(*size);
+            for &i in elems.iter() {
+                dense.set(i, true);
+            }
+            *self = HybridBitSet::Dense(dense);
+        }
+    }
+}
+
+impl BitSet for HybridBitSet {
+    type Iter<'a> = HybridBitSetIter<'a>;
+
+    fn empty(size: usize) -> Self {
+        HybridBitSet::Sparse(SmallVec::new(), size)
+    }
+
+    fn contains(&self, index: usize) -> bool {
+        match self {
+            HybridBitSet::Sparse(elems, _) => elems.binary_search(&index).is_ok(),
+            HybridBitSet::Dense(bits) => bits.contains(index),
+        }
+    }
+
+    fn insert(&mut self, index: usize) -> bool {
+        match self {
+            HybridBitSet::Sparse(elems, size) => match elems.binary_search(&index) {
+                Ok(_) => false,
+                Err(pos) => {
+                    elems.insert(pos, index);
+                    if elems.len() > SPARSE_MAX {
+                        let size = *size;
+                        let mut dense = BitVec::empty(size);
+                        for &i in elems.iter() {
+                            dense.set(i, true);
+                        }
+                        *self = HybridBitSet::Dense(dense);
+                    }
+                    true
+                }
+            },
+            HybridBitSet::Dense(bits) => {
+                let contained = bits.contains(index);
+                bits.set(index, true);
+                !contained
+            }
+        }
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        match self {
+            HybridBitSet::Sparse(elems, _) => HybridBitSetIter::Sparse(elems.iter()),
+            HybridBitSet::Dense(bits) => HybridBitSetIter::Dense(bits.iter()),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            HybridBitSet::Sparse(elems, _) => elems.len(),
+            HybridBitSet::Dense(bits) => BitSet::len(bits),
+        }
+    }
+
+    fn union(&mut self, other: &Self) -> bool {
+        if let (HybridBitSet::Dense(this), HybridBitSet::Dense(other)) = (&mut *self, other) {
+            return this.union(other);
+        }
+
+        let mut changed = false;
+        for i in other.iter() {
+            changed |= self.insert(i);
+        }
+        changed
+    }
+
+    fn intersect(&mut self, other: &Self) -> bool {
+        match (&mut *self, other) {
+            (HybridBitSet::Dense(this), HybridBitSet::Dense(other)) => this.intersect(other),
+            _ => {
+                let before = self.len();
+                let kept: SmallVec<[usize; SPARSE_MAX]> =
+                    self.iter().filter(|i| other.contains(*i)).collect();
+                let size = self.domain_size();
+                *self = HybridBitSet::Sparse(SmallVec::new(), size);
+                for i in kept {
+                    self.insert(i);
+                }
+                self.len() != before
+            }
+        }
+    }
+
+    fn subtract(&mut self, other: &Self) -> bool {
+        match (&mut *self, other) {
+            (HybridBitSet::Dense(this), HybridBitSet::Dense(other)) => this.subtract(other),
+            _ => {
+                let before = self.len();
+                let kept: SmallVec<[usize; SPARSE_MAX]> =
+                    self.iter().filter(|i| !other.contains(*i)).collect();
+                let size = self.domain_size();
+                *self = HybridBitSet::Sparse(SmallVec::new(), size);
+                for i in kept {
+                    self.insert(i);
+                }
+                self.len() != before
+            }
+        }
+    }
+
+    fn invert(&mut self) {
+        self.densify();
+        let HybridBitSet::Dense(bits) = self else {
+            unreachable!()
+        };
+        bits.invert();
+    }
+
+    fn clear(&mut self) {
+        let size = self.domain_size();
+        *self = HybridBitSet::Sparse(SmallVec::new(), size);
+    }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        if let (HybridBitSet::Dense(this), HybridBitSet::Dense(other)) = (self, other) {
+            return this.is_subset(other);
+        }
+        self.iter().all(|i| other.contains(i))
+    }
+
+    fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        if let (HybridBitSet::Dense(this), HybridBitSet::Dense(other)) = (self, other) {
+            return this.is_disjoint(other);
+        }
+        self.iter().all(|i| !other.contains(i))
+    }
+
+    fn intersect_len(&self, other: &Self) -> usize {
+        if let (HybridBitSet::Dense(this), HybridBitSet::Dense(other)) = (self, other) {
+            return this.intersect_len(other);
+        }
+        self.iter().filter(|i| other.contains(*i)).count()
+    }
+
+    fn grow(&mut self, new_size: usize) {
+        match self {
+            HybridBitSet::Sparse(_, size) => *size = new_size.max(*size),
+            HybridBitSet::Dense(bits) => bits.grow(new_size),
+        }
+    }
+}
+
+/// [`IndexSet`] specialized to the [`HybridBitSet`] implementation.
+pub type HybridIndexSet<T> = IndexSet<T, HybridBitSet, RcFamily>;
+
+/// [`IndexSet`] specialized to the [`HybridBitSet`] implementation with the [`ArcFamily`].
+pub type HybridArcIndexSet<T> = IndexSet<T, HybridBitSet, ArcFamily>;
+
+/// [`IndexMatrix`] specialized to the [`HybridBitSet`] implementation.
+pub type HybridIndexMatrix<R, C> = IndexMatrix<R, C, HybridBitSet, RcFamily>;
+
+/// [`IndexMatrix`] specialized to the [`HybridBitSet`] implementation with the [`ArcFamily`].
+pub type HybridArcIndexMatrix<R, C> = IndexMatrix<R, C, HybridBitSet, ArcFamily>;
+
+#[test]
+fn test_hybrid_bitset_relational_queries() {
+    let mut a = HybridBitSet::empty(8);
+    a.insert(0);
+    a.insert(1);
+
+    let mut b = HybridBitSet::empty(8);
+    b.insert(0);
+    b.insert(1);
+    b.insert(2);
+
+    assert!(a.is_subset(&b));
+    assert!(!b.is_subset(&a));
+    assert!(b.is_superset(&a));
+    assert_eq!(a.intersect_len(&b), 2);
+
+    let mut c = HybridBitSet::empty(8);
+    c.insert(3);
+    assert!(a.is_disjoint(&c));
+    assert!(!a.is_disjoint(&b));
+}
+
+#[test]
+fn test_hybrid_bitset() {
+    crate::test_utils::impl_test::<HybridBitSet>();
+}
+
+#[test]
+fn test_hybrid_bitset_grow() {
+    let mut set = HybridBitSet::empty(4);
+    set.insert(1);
+
+    set.grow(10);
+
+    assert!(set.contains(1));
+    set.insert(9);
+    assert!(set.contains(9));
+}
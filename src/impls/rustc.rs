@@ -3,7 +3,8 @@ pub extern crate rustc_index;
 extern crate rustc_mir_dataflow;
 
 use crate::{
-    ArcFamily, BitSet, IndexMatrix, IndexSet, IndexedValue, PointerFamily, RcFamily, RefFamily,
+    ArcFamily, BitSet, DenseIndexMatrix, IndexMatrix, IndexSet, IndexedValue, PointerFamily,
+    RcFamily, RefFamily,
 };
 use rustc_mir_dataflow::JoinSemiLattice;
 use std::hash::Hash;
@@ -74,6 +75,40 @@ impl BitSet for RustcBitSet {
     fn copy_from(&mut self, other: &Self) {
         self.clone_from(other);
     }
+
+    fn is_subset(&self, other: &Self) -> bool {
+        let mut remainder = self.clone();
+        remainder.subtract(other);
+        remainder.count() == 0
+    }
+
+    fn is_superset(&self, other: &Self) -> bool {
+        other.is_subset(self)
+    }
+
+    fn is_disjoint(&self, other: &Self) -> bool {
+        let mut intersection = self.clone();
+        intersection.intersect(other);
+        intersection.count() == 0
+    }
+
+    fn intersect_len(&self, other: &Self) -> usize {
+        let mut intersection = self.clone();
+        intersection.intersect(other);
+        intersection.count()
+    }
+
+    fn grow(&mut self, new_size: usize) {
+        if new_size <= self.domain_size() {
+            return;
+        }
+
+        let mut grown = RustcBitSet::new_empty(new_size);
+        for i in self.iter() {
+            grown.insert(i);
+        }
+        *self = grown;
+    }
 }
 
 /// [`IndexSet`] specialized to the `rustc_index::bit_set::BitSet` implementation.
@@ -121,7 +156,57 @@ where
     }
 }
 
+impl<R, C, S, P> JoinSemiLattice for DenseIndexMatrix<R, C, S, P>
+where
+    R: IndexedValue,
+    C: IndexedValue,
+    S: BitSet,
+    P: PointerFamily,
+{
+    fn join(&mut self, other: &Self) -> bool {
+        let mut changed = false;
+        for (row, other_row) in self.rows.iter_mut().zip(other.rows.iter()) {
+            changed |= row.union_changed(other_row);
+        }
+        changed
+    }
+}
+
 #[test]
 fn test_rustc_bitset() {
     crate::test_utils::impl_test::<RustcBitSet>();
 }
+
+#[test]
+fn test_rustc_bitset_relational_queries() {
+    let mut a = RustcBitSet::empty(8);
+    a.insert(0);
+    a.insert(1);
+
+    let mut b = RustcBitSet::empty(8);
+    b.insert(0);
+    b.insert(1);
+    b.insert(2);
+
+    assert!(BitSet::is_subset(&a, &b));
+    assert!(!BitSet::is_subset(&b, &a));
+    assert!(BitSet::is_superset(&b, &a));
+    assert_eq!(BitSet::intersect_len(&a, &b), 2);
+
+    let mut c = RustcBitSet::empty(8);
+    c.insert(3);
+    assert!(BitSet::is_disjoint(&a, &c));
+    assert!(!BitSet::is_disjoint(&a, &b));
+}
+
+#[test]
+fn test_rustc_bitset_grow() {
+    let mut set = RustcBitSet::empty(4);
+    set.insert(1);
+
+    BitSet::grow(&mut set, 10);
+
+    assert!(set.contains(1));
+    set.insert(9);
+    assert!(set.contains(9));
+}
@@ -85,6 +85,18 @@ where
     pub fn col_domain(&self) -> &P::Pointer<IndexedDomain<C>> {
         &self.col_domain
     }
+
+    /// Returns true if `row` in `self` is a subset of `row` in `other`, i.e. `other` already
+    /// entails everything `self` knows about `row`.
+    pub fn row_is_subset(&self, row: &R, other: &Self) -> bool {
+        self.row_set(row).is_subset(other.row_set(row))
+    }
+
+    /// Returns true if every row in `self` is a subset of the same row in `other`. Useful for
+    /// testing whether a dataflow state has stopped growing against its previous iteration.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.matrix.keys().all(|row| self.row_is_subset(row, other))
+    }
 }
 
 impl<R, C, S, P> PartialEq for IndexMatrix<R, C, S, P>
@@ -148,9 +160,179 @@ where
     }
 }
 
+/// A dense counterpart to [`IndexMatrix`], where rows live in a [`Vec`] indexed directly by a
+/// row [`IndexedDomain`] rather than in an [`FxHashMap`].
+///
+/// This trades the ability to use arbitrary hashable row types for removing per-access hashing:
+/// `row_set`/`ensure_row` become a direct index into `rows`, and [`DenseIndexMatrix::rows`]
+/// iterates in index order, which is friendlier to the cache. It's a drop-in substitute for
+/// [`IndexMatrix`] whenever the row set is itself indexable and bounded up front, which is the
+/// common case for dataflow problems.
+pub struct DenseIndexMatrix<R: IndexedValue, C: IndexedValue, S: BitSet, P: PointerFamily> {
+    pub(crate) rows: Vec<IndexSet<C, S, P>>,
+    empty_set: IndexSet<C, S, P>,
+    row_domain: P::Pointer<IndexedDomain<R>>,
+    col_domain: P::Pointer<IndexedDomain<C>>,
+}
+
+impl<R, C, S, P> DenseIndexMatrix<R, C, S, P>
+where
+    R: IndexedValue,
+    C: IndexedValue,
+    S: BitSet,
+    P: PointerFamily,
+{
+    /// Creates an empty matrix with one (empty) row per value in `row_domain`.
+    pub fn new(
+        row_domain: &P::Pointer<IndexedDomain<R>>,
+        col_domain: &P::Pointer<IndexedDomain<C>>,
+    ) -> Self {
+        let empty_set = IndexSet::new(col_domain);
+        DenseIndexMatrix {
+            rows: vec![empty_set.clone(); row_domain.len()],
+            empty_set,
+            row_domain: row_domain.clone(),
+            col_domain: col_domain.clone(),
+        }
+    }
+
+    pub(crate) fn ensure_row<M>(&mut self, row: impl ToIndex<R, M>) -> &mut IndexSet<C, S, P> {
+        let row = row.to_index(&self.row_domain);
+        &mut self.rows[row.index()]
+    }
+
+    /// Inserts a pair `(row, col)` into the matrix, returning true if `self` changed.
+    pub fn insert<M, N>(&mut self, row: impl ToIndex<R, M>, col: impl ToIndex<C, N>) -> bool {
+        let col = col.to_index(&self.col_domain);
+        self.ensure_row(row).insert(col)
+    }
+
+    /// Adds all elements of `from` into the row `into`.
+    pub fn union_into_row<M>(
+        &mut self,
+        into: impl ToIndex<R, M>,
+        from: &IndexSet<C, S, P>,
+    ) -> bool {
+        self.ensure_row(into).union_changed(from)
+    }
+
+    /// Adds all elements from the row `from` into the row `to`.
+    pub fn union_rows<M, N>(&mut self, from: impl ToIndex<R, M>, to: impl ToIndex<R, N>) -> bool {
+        let from = from.to_index(&self.row_domain).index();
+        let to = to.to_index(&self.row_domain).index();
+        if from == to {
+            return false;
+        }
+
+        let (from, to) = if from < to {
+            let (left, right) = self.rows.split_at_mut(to);
+            (&left[from], &mut right[0])
+        } else {
+            let (left, right) = self.rows.split_at_mut(from);
+            (&right[0], &mut left[to])
+        };
+        to.union_changed(from)
+    }
+
+    /// Returns an iterator over the elements in `row`.
+    pub fn row<M>(&self, row: impl ToIndex<R, M>) -> impl Iterator<Item = &C> + '_ {
+        self.rows[row.to_index(&self.row_domain).index()].iter()
+    }
+
+    /// Returns an iterator over all rows in the matrix, in index order.
+    pub fn rows(&self) -> impl Iterator<Item = (&R, &IndexSet<C, S, P>)> + '_ {
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(i, set)| (self.row_domain.value(i.into()), set))
+    }
+
+    /// Returns the [`IndexSet`] for a particular `row`.
+    pub fn row_set<M>(&self, row: impl ToIndex<R, M>) -> &IndexSet<C, S, P> {
+        &self.rows[row.to_index(&self.row_domain).index()]
+    }
+
+    /// Clears all the elements from the `row`.
+    pub fn clear_row<M>(&mut self, row: impl ToIndex<R, M>) {
+        self.rows[row.to_index(&self.row_domain).index()].clear();
+    }
+
+    /// Returns the [`IndexedDomain`] for the row type.
+    pub fn row_domain(&self) -> &P::Pointer<IndexedDomain<R>> {
+        &self.row_domain
+    }
+
+    /// Returns the [`IndexedDomain`] for the column type.
+    pub fn col_domain(&self) -> &P::Pointer<IndexedDomain<C>> {
+        &self.col_domain
+    }
+
+    /// Returns true if `row` in `self` is a subset of `row` in `other`.
+    pub fn row_is_subset<M>(&self, row: impl ToIndex<R, M> + Clone, other: &Self) -> bool {
+        self.row_set(row.clone()).is_subset(other.row_set(row))
+    }
+
+    /// Returns true if every row in `self` is a subset of the same row in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.rows
+            .iter()
+            .zip(other.rows.iter())
+            .all(|(this, other)| this.is_subset(other))
+    }
+}
+
+impl<R, C, S, P> Clone for DenseIndexMatrix<R, C, S, P>
+where
+    R: IndexedValue,
+    C: IndexedValue,
+    S: BitSet,
+    P: PointerFamily,
+{
+    fn clone(&self) -> Self {
+        Self {
+            rows: self.rows.clone(),
+            empty_set: self.empty_set.clone(),
+            row_domain: self.row_domain.clone(),
+            col_domain: self.col_domain.clone(),
+        }
+    }
+}
+
+impl<R, C, S, P> PartialEq for DenseIndexMatrix<R, C, S, P>
+where
+    R: IndexedValue,
+    C: IndexedValue,
+    S: BitSet,
+    P: PointerFamily,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.rows == other.rows
+    }
+}
+impl<R, C, S, P> Eq for DenseIndexMatrix<R, C, S, P>
+where
+    R: IndexedValue,
+    C: IndexedValue,
+    S: BitSet,
+    P: PointerFamily,
+{
+}
+
+impl<R, C, S, P> fmt::Debug for DenseIndexMatrix<R, C, S, P>
+where
+    R: IndexedValue + fmt::Debug,
+    C: IndexedValue + fmt::Debug,
+    S: BitSet,
+    P: PointerFamily,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.rows()).finish()
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{test_utils::TestIndexMatrix, IndexedDomain};
+    use crate::{test_utils::TestIndexMatrix, DenseIndexMatrix, IndexedDomain};
     use std::rc::Rc;
 
     #[test]
@@ -165,4 +347,41 @@ mod test {
         assert!(mtx.union_rows(0, 1));
         assert_eq!(mtx.row(&1).collect::<Vec<_>>(), vec![&"b", &"c"]);
     }
+
+    #[test]
+    fn test_dense_indexmatrix() {
+        let row_domain = Rc::new(IndexedDomain::from_iter(["r0", "r1"]));
+        let col_domain = Rc::new(IndexedDomain::from_iter(["a", "b", "c"]));
+        let mut mtx: DenseIndexMatrix<_, _, _, _> =
+            DenseIndexMatrix::new(&row_domain, &col_domain);
+        mtx.insert("r0", "b");
+        mtx.insert("r1", "c");
+        assert_eq!(mtx.row("r0").collect::<Vec<_>>(), vec![&"b"]);
+        assert_eq!(mtx.row("r1").collect::<Vec<_>>(), vec![&"c"]);
+
+        assert!(mtx.union_rows("r0", "r1"));
+        assert_eq!(mtx.row("r1").collect::<Vec<_>>(), vec![&"b", &"c"]);
+    }
+
+    #[test]
+    fn test_dense_indexmatrix_is_subset() {
+        let row_domain = Rc::new(IndexedDomain::from_iter(["r0", "r1"]));
+        let col_domain = Rc::new(IndexedDomain::from_iter(["a", "b", "c"]));
+
+        let mut smaller: DenseIndexMatrix<_, _, _, _> =
+            DenseIndexMatrix::new(&row_domain, &col_domain);
+        smaller.insert("r0", "a");
+
+        let mut bigger: DenseIndexMatrix<_, _, _, _> =
+            DenseIndexMatrix::new(&row_domain, &col_domain);
+        bigger.insert("r0", "a");
+        bigger.insert("r0", "b");
+        bigger.insert("r1", "c");
+
+        assert!(smaller.row_is_subset(&"r0", &bigger));
+        assert!(!bigger.row_is_subset(&"r0", &smaller));
+        assert!(smaller.is_subset(&bigger));
+        assert!(!bigger.is_subset(&smaller));
+        assert!(bigger.is_subset(&bigger));
+    }
 }
@@ -0,0 +1,62 @@
+use crate::{BitSet, IndexSet, IndexedValue, PointerFamily};
+
+/// Relational queries on [`IndexSet`], mirroring the [`BitSet`] queries of the same name.
+///
+/// These forward straight to the backing `S: BitSet`, the same way `IndexSet::union_changed`
+/// forwards to `S::union_changed` — so backends that can answer by ANDing/ORing words instead of
+/// walking elements (`RustcBitSet`, `BitVec`, ...) actually get used here, instead of every
+/// caller paying for a generic `iter`/`contains` walk regardless of backend.
+impl<T, S, P> IndexSet<T, S, P>
+where
+    T: IndexedValue,
+    S: BitSet,
+    P: PointerFamily,
+{
+    /// Returns true if every element of `self` is also in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.set.is_subset(&other.set)
+    }
+
+    /// Returns true if every element of `other` is also in `self`.
+    pub fn is_superset(&self, other: &Self) -> bool {
+        self.set.is_superset(&other.set)
+    }
+
+    /// Returns true if `self` and `other` share no elements.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.set.is_disjoint(&other.set)
+    }
+
+    /// Returns the number of elements common to `self` and `other`, without materializing the
+    /// intersection.
+    pub fn intersect_len(&self, other: &Self) -> usize {
+        self.set.intersect_len(&other.set)
+    }
+}
+
+#[test]
+fn test_index_set_relational_queries() {
+    use crate::impls::bv::BitvecIndexSet;
+    use crate::IndexedDomain;
+    use std::rc::Rc;
+
+    let domain = Rc::new(IndexedDomain::from_iter(["a", "b", "c", "d"]));
+    let mut a = BitvecIndexSet::new(&domain);
+    a.insert("a");
+    a.insert("b");
+
+    let mut b = BitvecIndexSet::new(&domain);
+    b.insert("a");
+    b.insert("b");
+    b.insert("c");
+
+    assert!(a.is_subset(&b));
+    assert!(!b.is_subset(&a));
+    assert!(b.is_superset(&a));
+    assert_eq!(a.intersect_len(&b), 2);
+
+    let mut c = BitvecIndexSet::new(&domain);
+    c.insert("d");
+    assert!(a.is_disjoint(&c));
+    assert!(!a.is_disjoint(&b));
+}